@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 use std::marker::PhantomData;
 
 pub trait Traversal<T1: ?Sized, T2: ?Sized> {
@@ -5,7 +7,7 @@ pub trait Traversal<T1: ?Sized, T2: ?Sized> {
     where
         T2: 'data,
         V: 'data,
-        F: FnOnce(&'data T2) -> V + 'data + Copy;
+        F: FnMut(&'data T2) -> V + 'data;
     fn with_mut<'data, V, F>(
         &'data self,
         data: &'data mut T1,
@@ -14,7 +16,71 @@ pub trait Traversal<T1: ?Sized, T2: ?Sized> {
     where
         T2: 'data,
         V: 'data,
-        F: FnOnce(&'data mut T2) -> V + 'data + Copy;
+        F: FnMut(&'data mut T2) -> V + 'data;
+
+    /// Eagerly applies `f` to every focused target, fully draining the
+    /// traversal so the mutation happens regardless of whether the caller
+    /// consumes any iterator (unlike `with_mut`, which only mutates targets
+    /// that are actually pulled from the returned iterator).
+    fn over<F>(&self, data: &mut T1, f: F)
+    where
+        F: FnMut(&mut T2);
+
+    /// Eagerly overwrites every focused target with a clone of `value`.
+    fn set(&self, data: &mut T1, value: T2)
+    where
+        T2: Clone,
+    {
+        self.over(data, |t2| *t2 = value.clone());
+    }
+
+    /// Threads `init` through `f`, once per focused target, without
+    /// allocating an intermediate iterator or `Vec`.
+    fn fold<Acc, F>(&self, data: &T1, init: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc;
+
+    /// Like [`fold`](Traversal::fold), but gives `f` mutable access to each
+    /// target.
+    fn fold_mut<Acc, F>(&self, data: &mut T1, init: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc;
+
+    /// Counts the number of focused targets.
+    fn count(&self, data: &T1) -> usize {
+        self.fold(data, 0, |acc, _| acc + 1)
+    }
+
+    /// Returns `true` if `p` holds for every focused target.
+    fn all<P>(&self, data: &T1, mut p: P) -> bool
+    where
+        P: FnMut(&T2) -> bool,
+    {
+        self.fold(data, true, |acc, t2| acc && p(t2))
+    }
+
+    /// Returns `true` if `p` holds for at least one focused target.
+    fn any<P>(&self, data: &T1, mut p: P) -> bool
+    where
+        P: FnMut(&T2) -> bool,
+    {
+        self.fold(data, false, |acc, t2| acc || p(t2))
+    }
+
+    /// Maps every focused target through `f` and collects the results into
+    /// `C`.
+    fn collect_into<C, V, F>(&self, data: &T1, mut f: F) -> C
+    where
+        C: FromIterator<V>,
+        F: FnMut(&T2) -> V,
+    {
+        self.fold(data, Vec::new(), |mut acc, t2| {
+            acc.push(f(t2));
+            acc
+        })
+        .into_iter()
+        .collect()
+    }
 }
 
 pub struct VecTraversal;
@@ -23,27 +89,528 @@ impl<T2> Traversal<Vec<T2>, T2> for VecTraversal {
     fn with<'data, V, F>(
         &'data self,
         data: &'data Vec<T2>,
-        f: F,
+        mut f: F,
     ) -> Box<dyn Iterator<Item = V> + 'data>
     where
         T2: 'data,
         V: 'data,
-        F: FnOnce(&'data T2) -> V + 'data + Copy,
+        F: FnMut(&'data T2) -> V + 'data,
     {
         Box::new(data.iter().map(move |t2| f(t2)))
     }
     fn with_mut<'data, V, F>(
         &'data self,
         data: &'data mut Vec<T2>,
-        f: F,
+        mut f: F,
     ) -> Box<dyn Iterator<Item = V> + 'data>
     where
         T2: 'data,
         V: 'data,
-        F: FnOnce(&'data mut T2) -> V + 'data + Copy,
+        F: FnMut(&'data mut T2) -> V + 'data,
     {
         Box::new(data.iter_mut().map(move |t2| f(t2)))
     }
+    fn over<F>(&self, data: &mut Vec<T2>, mut f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        for t2 in data.iter_mut() {
+            f(t2);
+        }
+    }
+    fn fold<Acc, F>(&self, data: &Vec<T2>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        data.iter().fold(init, |acc, t2| f(acc, t2))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut Vec<T2>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        data.iter_mut().fold(init, |acc, t2| f(acc, t2))
+    }
+}
+
+/// Focuses every value of a [`BTreeMap`], mirroring how the standard
+/// `Values`/`ValuesMut` iterators expose map internals.
+pub struct BTreeMapValues;
+
+impl<K: Ord, V> Traversal<BTreeMap<K, V>, V> for BTreeMapValues {
+    fn with<'data, Out, F>(
+        &'data self,
+        data: &'data BTreeMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data V) -> Out + 'data,
+    {
+        Box::new(data.values().map(move |v| f(v)))
+    }
+    fn with_mut<'data, Out, F>(
+        &'data self,
+        data: &'data mut BTreeMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data mut V) -> Out + 'data,
+    {
+        Box::new(data.values_mut().map(move |v| f(v)))
+    }
+    fn over<F>(&self, data: &mut BTreeMap<K, V>, mut f: F)
+    where
+        F: FnMut(&mut V),
+    {
+        for v in data.values_mut() {
+            f(v);
+        }
+    }
+    fn fold<Acc, F>(&self, data: &BTreeMap<K, V>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &V) -> Acc,
+    {
+        data.values().fold(init, |acc, v| f(acc, v))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut BTreeMap<K, V>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut V) -> Acc,
+    {
+        data.values_mut().fold(init, |acc, v| f(acc, v))
+    }
+}
+
+/// Focuses every value of a [`HashMap`], mirroring how the standard
+/// `Values`/`ValuesMut` iterators expose map internals.
+pub struct HashMapValues;
+
+impl<K: Eq + Hash, V> Traversal<HashMap<K, V>, V> for HashMapValues {
+    fn with<'data, Out, F>(
+        &'data self,
+        data: &'data HashMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data V) -> Out + 'data,
+    {
+        Box::new(data.values().map(move |v| f(v)))
+    }
+    fn with_mut<'data, Out, F>(
+        &'data self,
+        data: &'data mut HashMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data mut V) -> Out + 'data,
+    {
+        Box::new(data.values_mut().map(move |v| f(v)))
+    }
+    fn over<F>(&self, data: &mut HashMap<K, V>, mut f: F)
+    where
+        F: FnMut(&mut V),
+    {
+        for v in data.values_mut() {
+            f(v);
+        }
+    }
+    fn fold<Acc, F>(&self, data: &HashMap<K, V>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &V) -> Acc,
+    {
+        data.values().fold(init, |acc, v| f(acc, v))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut HashMap<K, V>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut V) -> Acc,
+    {
+        data.values_mut().fold(init, |acc, v| f(acc, v))
+    }
+}
+
+/// Focuses every key/value pair of a [`BTreeMap`], giving `(&K, &mut V)`-style
+/// access to each entry. Kept as inherent methods rather than a `Traversal`
+/// impl, since a `Traversal<T1, T2>` only ever exposes one target type with
+/// matching `&T2`/`&mut T2` access, and an entry needs the key to stay
+/// immutable while the value is mutable.
+pub struct BTreeMapEntries;
+
+impl BTreeMapEntries {
+    pub fn with<'data, K, V, Out, F>(
+        &'data self,
+        data: &'data BTreeMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        K: 'data,
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data K, &'data V) -> Out + 'data,
+    {
+        Box::new(data.iter().map(move |(k, v)| f(k, v)))
+    }
+    pub fn with_mut<'data, K, V, Out, F>(
+        &'data self,
+        data: &'data mut BTreeMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        K: 'data,
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data K, &'data mut V) -> Out + 'data,
+    {
+        Box::new(data.iter_mut().map(move |(k, v)| f(k, v)))
+    }
+}
+
+/// Focuses every key/value pair of a [`HashMap`], giving `(&K, &mut V)`-style
+/// access to each entry. See [`BTreeMapEntries`] for why this isn't a
+/// `Traversal` impl.
+pub struct HashMapEntries;
+
+impl HashMapEntries {
+    pub fn with<'data, K, V, Out, F>(
+        &'data self,
+        data: &'data HashMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        K: 'data,
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data K, &'data V) -> Out + 'data,
+    {
+        Box::new(data.iter().map(move |(k, v)| f(k, v)))
+    }
+    pub fn with_mut<'data, K, V, Out, F>(
+        &'data self,
+        data: &'data mut HashMap<K, V>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = Out> + 'data>
+    where
+        K: 'data,
+        V: 'data,
+        Out: 'data,
+        F: FnMut(&'data K, &'data mut V) -> Out + 'data,
+    {
+        Box::new(data.iter_mut().map(move |(k, v)| f(k, v)))
+    }
+}
+
+/// Focuses the 0-or-1 element contained in an [`Option`].
+pub struct OptionTraversal;
+
+impl<T2> Traversal<Option<T2>, T2> for OptionTraversal {
+    fn with<'data, V, F>(
+        &'data self,
+        data: &'data Option<T2>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data T2) -> V + 'data,
+    {
+        Box::new(data.iter().map(move |t2| f(t2)))
+    }
+    fn with_mut<'data, V, F>(
+        &'data self,
+        data: &'data mut Option<T2>,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data mut T2) -> V + 'data,
+    {
+        Box::new(data.iter_mut().map(move |t2| f(t2)))
+    }
+    fn over<F>(&self, data: &mut Option<T2>, mut f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        if let Some(t2) = data {
+            f(t2);
+        }
+    }
+    fn fold<Acc, F>(&self, data: &Option<T2>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        data.iter().fold(init, |acc, t2| f(acc, t2))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut Option<T2>, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        data.iter_mut().fold(init, |acc, t2| f(acc, t2))
+    }
+}
+
+/// Focuses every element of a fixed-size `[T; N]` array.
+pub struct ArrayTraversal<const N: usize>;
+
+impl<T2, const N: usize> Traversal<[T2; N], T2> for ArrayTraversal<N> {
+    fn with<'data, V, F>(
+        &'data self,
+        data: &'data [T2; N],
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data T2) -> V + 'data,
+    {
+        Box::new(data.iter().map(move |t2| f(t2)))
+    }
+    fn with_mut<'data, V, F>(
+        &'data self,
+        data: &'data mut [T2; N],
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data mut T2) -> V + 'data,
+    {
+        Box::new(data.iter_mut().map(move |t2| f(t2)))
+    }
+    fn over<F>(&self, data: &mut [T2; N], mut f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        for t2 in data.iter_mut() {
+            f(t2);
+        }
+    }
+    fn fold<Acc, F>(&self, data: &[T2; N], init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        data.iter().fold(init, |acc, t2| f(acc, t2))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut [T2; N], init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        data.iter_mut().fold(init, |acc, t2| f(acc, t2))
+    }
+}
+
+/// Wraps a traversal, only yielding targets for which `pred` holds.
+pub struct Filter<Tr, P, T2: ?Sized> {
+    tr: Tr,
+    pred: P,
+    _marker: PhantomData<T2>,
+}
+
+impl<Tr, P, T2: ?Sized> Filter<Tr, P, T2> {
+    pub fn new<T1: ?Sized>(tr: Tr, pred: P) -> Self
+    where
+        Tr: Traversal<T1, T2>,
+        P: Fn(&T2) -> bool,
+    {
+        Self {
+            tr,
+            pred,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Tr, P, T1, T2> Traversal<T1, T2> for Filter<Tr, P, T2>
+where
+    T1: ?Sized,
+    T2: ?Sized,
+    Tr: Traversal<T1, T2>,
+    P: Fn(&T2) -> bool,
+{
+    fn with<'data, V, F>(
+        &'data self,
+        data: &'data T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data T2) -> V + 'data,
+    {
+        let pred = &self.pred;
+        Box::new(
+            self.tr
+                .with(data, move |t2: &'data T2| pred(t2).then(|| f(t2)))
+                .flatten(),
+        )
+    }
+    fn with_mut<'data, V, F>(
+        &'data self,
+        data: &'data mut T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data mut T2) -> V + 'data,
+    {
+        let pred = &self.pred;
+        Box::new(
+            self.tr
+                .with_mut(data, move |t2: &'data mut T2| pred(t2).then(|| f(t2)))
+                .flatten(),
+        )
+    }
+    fn over<F>(&self, data: &mut T1, mut f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        let pred = &self.pred;
+        self.tr.over(data, |t2| {
+            if pred(t2) {
+                f(t2);
+            }
+        });
+    }
+    fn fold<Acc, F>(&self, data: &T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        let pred = &self.pred;
+        self.tr.fold(
+            data,
+            init,
+            |acc, t2| if pred(t2) { f(acc, t2) } else { acc },
+        )
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        let pred = &self.pred;
+        self.tr.fold_mut(
+            data,
+            init,
+            |acc, t2| if pred(t2) { f(acc, t2) } else { acc },
+        )
+    }
+}
+
+/// Wraps a traversal, passing each target's zero-based position in
+/// traversal order alongside the target itself.
+///
+/// This is *not* what its [`Traversal`] impl below does: the trait's
+/// `with`/`with_mut` closures only ever receive a single `&T2`/`&mut T2`, so
+/// there's no way to also hand them an index through that interface. The
+/// `Traversal` impl below therefore just delegates straight through to the
+/// wrapped traversal (so an `Indexed<Tr>` composes under [`Then`] exactly
+/// like `Tr` would), and the index is instead surfaced through the separate,
+/// non-trait [`Indexed::with_indexed`] and [`Indexed::with_indexed_mut`]
+/// methods, which — like every other lazy traversal method in this module —
+/// return a `Box<dyn Iterator>` rather than collecting eagerly.
+pub struct Indexed<Tr> {
+    tr: Tr,
+}
+
+impl<Tr> Indexed<Tr> {
+    pub fn new(tr: Tr) -> Self {
+        Self { tr }
+    }
+
+    /// Like [`Traversal::with`], but also passes each target's zero-based
+    /// position in traversal order to `f`.
+    pub fn with_indexed<'data, T1, T2, V, F>(
+        &'data self,
+        data: &'data T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T1: ?Sized,
+        Tr: Traversal<T1, T2>,
+        T2: 'data,
+        V: 'data,
+        F: FnMut(usize, &'data T2) -> V + 'data,
+    {
+        Box::new(
+            self.tr
+                .with(data, |t2: &'data T2| t2)
+                .enumerate()
+                .map(move |(i, t2)| f(i, t2)),
+        )
+    }
+
+    /// Like [`Traversal::with_mut`], but also passes each target's
+    /// zero-based position in traversal order to `f`.
+    pub fn with_indexed_mut<'data, T1, T2, V, F>(
+        &'data self,
+        data: &'data mut T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T1: ?Sized,
+        Tr: Traversal<T1, T2>,
+        T2: 'data,
+        V: 'data,
+        F: FnMut(usize, &'data mut T2) -> V + 'data,
+    {
+        Box::new(
+            self.tr
+                .with_mut(data, |t2: &'data mut T2| t2)
+                .enumerate()
+                .map(move |(i, t2)| f(i, t2)),
+        )
+    }
+}
+
+impl<Tr, T1, T2> Traversal<T1, T2> for Indexed<Tr>
+where
+    T1: ?Sized,
+    T2: ?Sized,
+    Tr: Traversal<T1, T2>,
+{
+    fn with<'data, V, F>(&'data self, data: &'data T1, f: F) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data T2) -> V + 'data,
+    {
+        self.tr.with(data, f)
+    }
+    fn with_mut<'data, V, F>(
+        &'data self,
+        data: &'data mut T1,
+        f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data mut T2) -> V + 'data,
+    {
+        self.tr.with_mut(data, f)
+    }
+    fn over<F>(&self, data: &mut T1, f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        self.tr.over(data, f)
+    }
+    fn fold<Acc, F>(&self, data: &T1, init: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        self.tr.fold(data, init, f)
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut T1, init: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        self.tr.fold_mut(data, init, f)
+    }
 }
 
 impl<Tr1, Tr2, T1, T2, T3> Traversal<T1, T3> for Then<Tr1, Tr2, T2>
@@ -54,15 +621,34 @@ where
     Tr1: Traversal<T1, T2>,
     Tr2: Traversal<T2, T3>,
 {
-    fn with<'data, V, F>(&'data self, data: &'data T1, f: F) -> Box<dyn Iterator<Item = V> + 'data>
+    // `with`/`with_mut` can't be built by nesting `self.right.with(...)`
+    // inside `self.left.with(...)` and flattening while handing `&mut f`
+    // straight through: each left target would need its own reborrow of
+    // `f`, and every one of those reborrows would need to outlive `'data`
+    // to type-check as the `F` of the inner `with` call, which only one
+    // reborrow at a time can satisfy. Instead, share `f` through a single
+    // `Rc<RefCell<F>>`: every inner closure only captures a clone of the
+    // `Rc` (an owned, 'static-ish handle, not a borrow of `f` itself), so
+    // any number of them can coexist, and `flatten` still drives the whole
+    // chain lazily, one target at a time, in traversal order.
+    fn with<'data, V, F>(
+        &'data self,
+        data: &'data T1,
+        f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
     where
         T3: 'data,
         V: 'data,
-        F: FnOnce(&'data T3) -> V + 'data + Copy,
+        F: FnMut(&'data T3) -> V + 'data,
     {
+        let f = std::rc::Rc::new(std::cell::RefCell::new(f));
+        let right = &self.right;
         Box::new(
             self.left
-                .with(data, move |b: &'data T2| self.right.with(b, f))
+                .with(data, move |t2: &'data T2| {
+                    let f = f.clone();
+                    right.with(t2, move |t3: &'data T3| (f.borrow_mut())(t3))
+                })
                 .flatten(),
         )
     }
@@ -74,14 +660,41 @@ where
     where
         T3: 'data,
         V: 'data,
-        F: FnOnce(&'data mut T3) -> V + 'data + Copy,
+        F: FnMut(&'data mut T3) -> V + 'data,
     {
+        let f = std::rc::Rc::new(std::cell::RefCell::new(f));
+        let right = &self.right;
         Box::new(
             self.left
-                .with_mut(data, move |b: &'data mut T2| self.right.with_mut(b, f))
+                .with_mut(data, move |t2: &'data mut T2| {
+                    let f = f.clone();
+                    right.with_mut(t2, move |t3: &'data mut T3| (f.borrow_mut())(t3))
+                })
                 .flatten(),
         )
     }
+    fn over<F>(&self, data: &mut T1, mut f: F)
+    where
+        F: FnMut(&mut T3),
+    {
+        let right = &self.right;
+        self.left.over(data, |t2: &mut T2| right.over(t2, &mut f));
+    }
+    fn fold<Acc, F>(&self, data: &T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T3) -> Acc,
+    {
+        let right = &self.right;
+        self.left.fold(data, init, |acc, t2| right.fold(t2, acc, &mut f))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T3) -> Acc,
+    {
+        let right = &self.right;
+        self.left
+            .fold_mut(data, init, |acc, t2| right.fold_mut(t2, acc, &mut f))
+    }
 }
 
 #[derive(Debug, Copy, PartialEq)]
@@ -115,6 +728,211 @@ impl<Tr1: Clone, Tr2: Clone, T2> Clone for Then<Tr1, Tr2, T2> {
     }
 }
 
+/// A total, bidirectional focus on exactly one `T2` inside a `T1`, built
+/// from a pair of accessor functions.
+///
+/// Unlike the traversals above, `Lens` never misses: `get`/`get_mut` always
+/// find their target, so it implements [`Traversal`] directly below by
+/// treating that single target as a one-element traversal. This lets a
+/// `Lens` compose with any other optic in this module through [`Then`].
+pub struct Lens<FGet, FGetMut, T2: ?Sized> {
+    getter: FGet,
+    getter_mut: FGetMut,
+    _marker: PhantomData<T2>,
+}
+
+impl<FGet, FGetMut, T2: ?Sized> Lens<FGet, FGetMut, T2> {
+    pub fn new<T1: ?Sized>(getter: FGet, getter_mut: FGetMut) -> Self
+    where
+        for<'data> FGet: Fn(&'data T1) -> &'data T2,
+        for<'data> FGetMut: Fn(&'data mut T1) -> &'data mut T2,
+    {
+        Self {
+            getter,
+            getter_mut,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn get<'data, T1: ?Sized>(&self, data: &'data T1) -> &'data T2
+    where
+        FGet: Fn(&'data T1) -> &'data T2,
+    {
+        (self.getter)(data)
+    }
+
+    pub fn get_mut<'data, T1: ?Sized>(&self, data: &'data mut T1) -> &'data mut T2
+    where
+        FGetMut: Fn(&'data mut T1) -> &'data mut T2,
+    {
+        (self.getter_mut)(data)
+    }
+}
+
+impl<FGet, FGetMut, T1: ?Sized, T2: ?Sized> Traversal<T1, T2> for Lens<FGet, FGetMut, T2>
+where
+    for<'data> FGet: Fn(&'data T1) -> &'data T2,
+    for<'data> FGetMut: Fn(&'data mut T1) -> &'data mut T2,
+{
+    fn with<'data, V, F>(
+        &'data self,
+        data: &'data T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data T2) -> V + 'data,
+    {
+        Box::new(std::iter::once_with(move || f((self.getter)(data))))
+    }
+    fn with_mut<'data, V, F>(
+        &'data self,
+        data: &'data mut T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data mut T2) -> V + 'data,
+    {
+        Box::new(std::iter::once_with(move || f((self.getter_mut)(data))))
+    }
+    fn over<F>(&self, data: &mut T1, mut f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        f((self.getter_mut)(data));
+    }
+    fn fold<Acc, F>(&self, data: &T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        f(init, (self.getter)(data))
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        f(init, (self.getter_mut)(data))
+    }
+}
+
+/// A partial, bidirectional focus on an optional `T2` inside a `T1` — the
+/// asymmetric counterpart to [`Lens`].
+///
+/// A `Prism` may have zero targets (e.g. `preview` returning `None` for the
+/// wrong enum variant), so it implements [`Traversal`] directly below the
+/// same way [`OptionTraversal`] does. Unlike a plain traversal, a `Prism`
+/// also carries `build`, letting a `T1` be reconstructed from a lone `T2`
+/// without needing the rest of the original `T1` (e.g. wrapping a value
+/// back into its enum variant).
+pub struct Prism<FPreview, FPreviewMut, FBuild, T2: ?Sized> {
+    previewer: FPreview,
+    previewer_mut: FPreviewMut,
+    builder: FBuild,
+    _marker: PhantomData<T2>,
+}
+
+impl<FPreview, FPreviewMut, FBuild, T2: ?Sized> Prism<FPreview, FPreviewMut, FBuild, T2> {
+    pub fn new<T1>(previewer: FPreview, previewer_mut: FPreviewMut, builder: FBuild) -> Self
+    where
+        for<'data> FPreview: Fn(&'data T1) -> Option<&'data T2>,
+        for<'data> FPreviewMut: Fn(&'data mut T1) -> Option<&'data mut T2>,
+        T2: Sized,
+        FBuild: Fn(T2) -> T1,
+    {
+        Self {
+            previewer,
+            previewer_mut,
+            builder,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn preview<'data, T1: ?Sized>(&self, data: &'data T1) -> Option<&'data T2>
+    where
+        FPreview: Fn(&'data T1) -> Option<&'data T2>,
+    {
+        (self.previewer)(data)
+    }
+
+    pub fn preview_mut<'data, T1: ?Sized>(&self, data: &'data mut T1) -> Option<&'data mut T2>
+    where
+        FPreviewMut: Fn(&'data mut T1) -> Option<&'data mut T2>,
+    {
+        (self.previewer_mut)(data)
+    }
+
+    /// Reconstructs a `T1` from a lone `T2`, without needing an existing
+    /// `T1` to focus into.
+    pub fn build<T1>(&self, value: T2) -> T1
+    where
+        T2: Sized,
+        FBuild: Fn(T2) -> T1,
+    {
+        (self.builder)(value)
+    }
+}
+
+impl<FPreview, FPreviewMut, FBuild, T1: ?Sized, T2: ?Sized> Traversal<T1, T2>
+    for Prism<FPreview, FPreviewMut, FBuild, T2>
+where
+    for<'data> FPreview: Fn(&'data T1) -> Option<&'data T2>,
+    for<'data> FPreviewMut: Fn(&'data mut T1) -> Option<&'data mut T2>,
+{
+    fn with<'data, V, F>(
+        &'data self,
+        data: &'data T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data T2) -> V + 'data,
+    {
+        Box::new((self.previewer)(data).into_iter().map(move |t2| f(t2)))
+    }
+    fn with_mut<'data, V, F>(
+        &'data self,
+        data: &'data mut T1,
+        mut f: F,
+    ) -> Box<dyn Iterator<Item = V> + 'data>
+    where
+        T2: 'data,
+        V: 'data,
+        F: FnMut(&'data mut T2) -> V + 'data,
+    {
+        Box::new((self.previewer_mut)(data).into_iter().map(move |t2| f(t2)))
+    }
+    fn over<F>(&self, data: &mut T1, mut f: F)
+    where
+        F: FnMut(&mut T2),
+    {
+        if let Some(t2) = (self.previewer_mut)(data) {
+            f(t2);
+        }
+    }
+    fn fold<Acc, F>(&self, data: &T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &T2) -> Acc,
+    {
+        match (self.previewer)(data) {
+            Some(t2) => f(init, t2),
+            None => init,
+        }
+    }
+    fn fold_mut<Acc, F>(&self, data: &mut T1, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut T2) -> Acc,
+    {
+        match (self.previewer_mut)(data) {
+            Some(t2) => f(init, t2),
+            None => init,
+        }
+    }
+}
+
 // cargo test optics::traversal::traversal::test_vec_traversal_with -- --exact
 #[test]
 fn test_vec_traversal_with() {
@@ -176,3 +994,213 @@ fn test_vec_traversal_with_mut() {
         ]
     );
 }
+
+// cargo test optics::traversal::traversal::test_vec_traversal_over_and_set -- --exact
+#[test]
+fn test_vec_traversal_over_and_set() {
+    let mut v = vec![0u8, 1, 2];
+    // unlike `with_mut`, `over` applies regardless of whether the result is consumed
+    VecTraversal.over(&mut v, |t2| *t2 += 1);
+    assert_eq!(v, vec![1, 2, 3]);
+
+    let mut v2 = vec![vec![0, 1, 2], vec![10, 11, 12]];
+    let trav = Then::new(VecTraversal, VecTraversal);
+    trav.over(&mut v2, |t2| *t2 += 1);
+    assert_eq!(v2, vec![vec![1, 2, 3], vec![11, 12, 13]]);
+
+    trav.set(&mut v2, 7);
+    assert_eq!(v2, vec![vec![7, 7, 7], vec![7, 7, 7]]);
+}
+
+// cargo test optics::traversal::traversal::test_vec_traversal_fold -- --exact
+#[test]
+fn test_vec_traversal_fold() {
+    let v = vec![0u8, 1, 2];
+    let sum = VecTraversal.fold(&v, 0u32, |acc, t2| acc + *t2 as u32);
+    assert_eq!(sum, 3);
+    assert_eq!(VecTraversal.count(&v), 3);
+    assert!(VecTraversal.all(&v, |t2| *t2 < 10));
+    assert!(VecTraversal.any(&v, |t2| *t2 == 1));
+    assert!(!VecTraversal.any(&v, |t2| *t2 == 10));
+    let doubled: Vec<u8> = VecTraversal.collect_into(&v, |t2| *t2 * 2);
+    assert_eq!(doubled, vec![0, 2, 4]);
+
+    let v2 = vec![vec![0u8, 1, 2], vec![10, 11, 12]];
+    let trav = Then::new(VecTraversal, VecTraversal);
+    let sum2 = trav.fold(&v2, 0u32, |acc, t2| acc + *t2 as u32);
+    assert_eq!(sum2, 0 + 1 + 2 + 10 + 11 + 12);
+    assert_eq!(trav.count(&v2), 6);
+
+    let mut v3 = vec![vec![0u8, 1, 2], vec![10, 11, 12]];
+    let sum3 = trav.fold_mut(&mut v3, 0u32, |acc, t2| {
+        *t2 += 1;
+        acc + *t2 as u32
+    });
+    assert_eq!(sum3, 1 + 2 + 3 + 11 + 12 + 13);
+    assert_eq!(v3, vec![vec![1, 2, 3], vec![11, 12, 13]]);
+}
+
+// cargo test optics::traversal::traversal::test_map_option_array_traversals -- --exact
+#[test]
+fn test_map_option_array_traversals() {
+    let mut map: BTreeMap<&str, Vec<u8>> = BTreeMap::new();
+    map.insert("a", vec![0u8, 1]);
+    map.insert("b", vec![10u8]);
+    let trav = Then::new::<BTreeMap<&str, Vec<u8>>, u8>(BTreeMapValues, VecTraversal);
+    trav.over(&mut map, |t2| *t2 += 1);
+    assert_eq!(map[&"a"], vec![1, 2]);
+    assert_eq!(map[&"b"], vec![11]);
+
+    let mut hash_map: HashMap<&str, Vec<u8>> = HashMap::new();
+    hash_map.insert("a", vec![0u8, 1, 2]);
+    let hash_trav = Then::new::<HashMap<&str, Vec<u8>>, u8>(HashMapValues, VecTraversal);
+    assert_eq!(hash_trav.count(&hash_map), 3);
+
+    let keys: Vec<_> = BTreeMapEntries
+        .with(&map, |k, v: &Vec<u8>| (*k, v.len()))
+        .collect();
+    assert_eq!(keys, vec![("a", 2), ("b", 1)]);
+
+    let mut some: Option<u8> = Some(41);
+    OptionTraversal.over(&mut some, |t2| *t2 += 1);
+    assert_eq!(some, Some(42));
+    let mut none: Option<u8> = None;
+    OptionTraversal.over(&mut none, |t2| *t2 += 1);
+    assert_eq!(none, None);
+    assert_eq!(OptionTraversal.count(&some), 1);
+    assert_eq!(OptionTraversal.count(&none), 0);
+
+    let mut arr = [0u8, 1, 2];
+    ArrayTraversal.over(&mut arr, |t2| *t2 += 1);
+    assert_eq!(arr, [1, 2, 3]);
+    assert_eq!(
+        ArrayTraversal.fold(&arr, 0u32, |acc, t2| acc + *t2 as u32),
+        6
+    );
+}
+
+// cargo test optics::traversal::traversal::test_filter_and_indexed_traversals -- --exact
+#[test]
+fn test_filter_and_indexed_traversals() {
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn is_even(t2: &u8) -> bool {
+        *t2 % 2 == 0
+    }
+
+    let v = vec![0u8, 1, 2, 3, 4];
+    let evens: Vec<_> = Filter::new(VecTraversal, is_even)
+        .with(&v, |t2| *t2)
+        .collect();
+    assert_eq!(evens, vec![0, 2, 4]);
+
+    let mut v2 = vec![vec![0u8, 1, 2], vec![3, 4, 5]];
+    let trav = Then::new(VecTraversal, Filter::new(VecTraversal, is_even));
+    trav.over(&mut v2, |t2| *t2 += 100);
+    assert_eq!(v2, vec![vec![100, 1, 102], vec![3, 104, 5]]);
+    assert_eq!(trav.count(&v2), 3);
+
+    let v3 = vec![10u8, 20, 30];
+    let indexed = Indexed::new(VecTraversal);
+    let pairs: Vec<_> = indexed.with_indexed(&v3, |i, t2| (i, *t2)).collect();
+    assert_eq!(pairs, vec![(0, 10), (1, 20), (2, 30)]);
+
+    let mut v4 = vec![10u8, 20, 30];
+    // like `Traversal::with_mut`, stays lazy: only applies to targets the
+    // returned iterator is actually driven over.
+    let res = indexed.with_indexed_mut(&mut v4, |i, t2| *t2 += i as u8);
+    let () = res.take(2).collect();
+    assert_eq!(v4, vec![10, 21, 30]);
+
+    // Indexed still composes as a plain Traversal under Then.
+    let mut v5 = vec![vec![0u8, 1], vec![2, 3]];
+    let trav2 = Then::new(VecTraversal, Indexed::new(VecTraversal));
+    trav2.over(&mut v5, |t2| *t2 += 1);
+    assert_eq!(v5, vec![vec![1, 2], vec![3, 4]]);
+
+    // `Filter`'s predicate only needs to be called through a shared
+    // reference, so it doesn't need `Copy` — a predicate capturing owned,
+    // non-`Copy` state works just as well as a plain fn pointer.
+    let threshold = String::from("b");
+    let v6 = vec!["a", "b", "c"];
+    let above_threshold: Vec<_> =
+        Filter::new(VecTraversal, move |t2: &&str| *t2 >= threshold.as_str())
+            .with(&v6, |t2| *t2)
+            .collect();
+    assert_eq!(above_threshold, vec!["b", "c"]);
+}
+
+// cargo test optics::traversal::traversal::test_lens_and_prism -- --exact
+#[test]
+fn test_lens_and_prism() {
+    struct Point {
+        x: u8,
+        values: Vec<u8>,
+    }
+
+    let x_lens: Lens<_, _, u8> = Lens::new(|p: &Point| &p.x, |p: &mut Point| &mut p.x);
+
+    let mut p = Point {
+        x: 1,
+        values: vec![10, 20],
+    };
+    assert_eq!(*x_lens.get(&p), 1);
+    x_lens.over(&mut p, |x| *x += 1);
+    assert_eq!(p.x, 2);
+    assert_eq!(x_lens.count(&p), 1);
+
+    // Like every other `Traversal` impl, `with_mut` stays lazy: dropping the
+    // returned iterator without consuming it must not mutate `p.x`. Scope
+    // the unconsumed iterator in its own block so its borrow of `p` ends
+    // before the next access (it's a trait object, so the borrow checker
+    // can't otherwise tell it ends early).
+    {
+        let _unconsumed = x_lens.with_mut(&mut p, |x| *x += 1);
+    }
+    assert_eq!(p.x, 2);
+
+    // Lens composes with other traversals through Then, just like any
+    // other Traversal.
+    let values_lens: Lens<_, _, Vec<u8>> =
+        Lens::new(|p: &Point| &p.values, |p: &mut Point| &mut p.values);
+    let trav = Then::new(values_lens, VecTraversal);
+    trav.over(&mut p, |v| *v += 1);
+    assert_eq!(p.values, vec![11, 21]);
+
+    enum Shape {
+        Circle(u8),
+        Square(u8),
+    }
+
+    let circle_prism: Prism<_, _, _, u8> = Prism::new(
+        |s: &Shape| match s {
+            Shape::Circle(r) => Some(r),
+            Shape::Square(_) => None,
+        },
+        |s: &mut Shape| match s {
+            Shape::Circle(r) => Some(r),
+            Shape::Square(_) => None,
+        },
+        Shape::Circle,
+    );
+
+    let mut circle = Shape::Circle(5);
+    assert_eq!(circle_prism.preview(&circle), Some(&5));
+    circle_prism.over(&mut circle, |r| *r += 1);
+    assert_eq!(circle_prism.preview(&circle), Some(&6));
+    assert_eq!(circle_prism.count(&circle), 1);
+
+    // Same laziness guarantee as `Lens::with_mut`, for the `Some` branch.
+    {
+        let _unconsumed = circle_prism.with_mut(&mut circle, |r| *r += 1);
+    }
+    assert_eq!(circle_prism.preview(&circle), Some(&6));
+
+    let mut square = Shape::Square(9);
+    assert_eq!(circle_prism.preview(&square), None);
+    circle_prism.over(&mut square, |r| *r += 1);
+    assert!(matches!(square, Shape::Square(9)));
+    assert_eq!(circle_prism.count(&square), 0);
+
+    let built: Shape = circle_prism.build(42);
+    assert!(matches!(built, Shape::Circle(42)));
+}